@@ -0,0 +1,390 @@
+//! Animation objects.
+
+use fbxcel::low::v7400::AttributeValue;
+
+use crate::v7400::object::{
+    property::{loaders::PrimitiveLoader, ObjectProperties},
+    ObjectHandle, TypedObjectHandle,
+};
+use crate::v7400::Result;
+
+/// Number of FBX time units in a single second.
+pub const FBX_TIME_UNITS_PER_SECOND: i64 = 46_186_158_000;
+
+/// A point in time, measured in FBX time units.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct FbxTime(pub i64);
+
+impl FbxTime {
+    /// Creates a time from a count of raw FBX time units.
+    pub fn from_raw(raw: i64) -> Self {
+        FbxTime(raw)
+    }
+
+    /// Creates a time from a number of seconds.
+    pub fn from_seconds(seconds: f64) -> Self {
+        FbxTime((seconds * FBX_TIME_UNITS_PER_SECOND as f64) as i64)
+    }
+
+    /// Returns the raw FBX time units.
+    pub fn raw(self) -> i64 {
+        self.0
+    }
+
+    /// Returns the time in seconds.
+    pub fn as_seconds(self) -> f64 {
+        self.0 as f64 / FBX_TIME_UNITS_PER_SECOND as f64
+    }
+}
+
+/// FBX key interpolation flag: constant hold.
+const KEY_INTERP_CONSTANT: i32 = 0x0000_0002;
+/// FBX key interpolation flag: cubic.
+const KEY_INTERP_CUBIC: i32 = 0x0000_0008;
+
+define_object_subtype! {
+    /// `AnimationCurve` node handle.
+    AnimationCurveHandle: ObjectHandle
+}
+
+impl<'a> AnimationCurveHandle<'a> {
+    /// Returns the key times.
+    pub fn key_times(&self) -> Result<Vec<i64>> {
+        match self.array_child("KeyTime")? {
+            Some(AttributeValue::ArrI64(arr)) => Ok(arr.to_vec()),
+            Some(attr) => Err(error!("expected i64 array for `KeyTime` but got {:?}", attr)),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Returns the key values.
+    pub fn key_values(&self) -> Result<Vec<f32>> {
+        match self.array_child("KeyValueFloat")? {
+            Some(AttributeValue::ArrF32(arr)) => Ok(arr.to_vec()),
+            Some(attr) => Err(error!("expected f32 array for `KeyValueFloat` but got {:?}", attr)),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Returns the per-attribute-entry interpolation/tangent flags.
+    pub fn key_attr_flags(&self) -> Result<Vec<i32>> {
+        match self.array_child("KeyAttrFlags")? {
+            Some(AttributeValue::ArrI32(arr)) => Ok(arr.to_vec()),
+            Some(attr) => Err(error!("expected i32 array for `KeyAttrFlags` but got {:?}", attr)),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Returns the packed per-attribute-entry tangent data.
+    pub fn key_attr_data_float(&self) -> Result<Vec<f32>> {
+        match self.array_child("KeyAttrDataFloat")? {
+            Some(AttributeValue::ArrF32(arr)) => Ok(arr.to_vec()),
+            Some(attr) => Err(error!(
+                "expected f32 array for `KeyAttrDataFloat` but got {:?}",
+                attr
+            )),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Returns the run-length reference counts mapping keys to attribute entries.
+    pub fn key_attr_ref_count(&self) -> Result<Vec<i32>> {
+        match self.array_child("KeyAttrRefCount")? {
+            Some(AttributeValue::ArrI32(arr)) => Ok(arr.to_vec()),
+            Some(attr) => Err(error!(
+                "expected i32 array for `KeyAttrRefCount` but got {:?}",
+                attr
+            )),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Evaluates the curve at the given time.
+    ///
+    /// The bracketing keys are found by binary search. Segments flagged
+    /// constant hold the left value, cubic segments are interpolated with a
+    /// Hermite spline from the stored tangents, and everything else is treated
+    /// as linear. Times outside the key range clamp to the nearest endpoint.
+    pub fn evaluate(&self, time: FbxTime) -> Result<f32> {
+        let times = self.key_times()?;
+        let values = self.key_values()?;
+        let flags = self.key_attr_flags().unwrap_or_default();
+        let data = self.key_attr_data_float().unwrap_or_default();
+        let ref_counts = self.key_attr_ref_count().unwrap_or_default();
+        Ok(evaluate_keys(
+            &times,
+            &values,
+            &flags,
+            &data,
+            &ref_counts,
+            time.raw(),
+        ))
+    }
+}
+
+/// Maps a key index to its attribute entry via the run-length `KeyAttrRefCount`.
+///
+/// FBX shares interpolation flags and tangent data across runs of keys: entry
+/// `e` covers `ref_counts[e]` consecutive keys. Returns `0` when no reference
+/// counts are present (the single-shared-entry case).
+fn attr_entry(ref_counts: &[i32], key: usize) -> usize {
+    if ref_counts.is_empty() {
+        return 0;
+    }
+    let mut covered = 0usize;
+    for (entry, &count) in ref_counts.iter().enumerate() {
+        covered += count.max(0) as usize;
+        if key < covered {
+            return entry;
+        }
+    }
+    ref_counts.len() - 1
+}
+
+/// Evaluates a curve from its raw key arrays at time `t` (in FBX time units).
+///
+/// The bracketing keys are found by binary search. Segments flagged constant
+/// hold the left value, cubic segments use a Hermite spline built from the
+/// stored tangents, and everything else is linear. Times outside the key range
+/// clamp to the nearest endpoint.
+fn evaluate_keys(
+    times: &[i64],
+    values: &[f32],
+    flags: &[i32],
+    data: &[f32],
+    ref_counts: &[i32],
+    t: i64,
+) -> f32 {
+    if times.is_empty() || values.is_empty() {
+        return 0.0;
+    }
+    if t <= times[0] {
+        return values[0];
+    }
+    if t >= times[times.len() - 1] {
+        return values[values.len() - 1];
+    }
+
+    // `times` is sorted, so the bracketing index is the last key not after `t`.
+    let right = times.partition_point(|&kt| kt <= t);
+    let left = right - 1;
+    let (t0, t1) = (times[left], times[right]);
+    let (v0, v1) = (values[left], values[right]);
+
+    let entry = attr_entry(ref_counts, left);
+    let flag = flags.get(entry).copied().unwrap_or(0);
+    if flag & KEY_INTERP_CONSTANT != 0 {
+        return v0;
+    }
+
+    let s = (t - t0) as f32 / (t1 - t0) as f32;
+    if flag & KEY_INTERP_CUBIC != 0 {
+        if let (Some(&m0), Some(&m1)) = (data.get(entry * 4), data.get(entry * 4 + 1)) {
+            let span_secs = FbxTime(t1 - t0).as_seconds() as f32;
+            return hermite(v0, v1, m0, m1, s, span_secs);
+        }
+    }
+    v0 + (v1 - v0) * s
+}
+
+/// Evaluates a cubic Hermite segment.
+///
+/// `m0`/`m1` are the end slopes in value-per-second and `span_secs` is the
+/// segment length in seconds, so the slopes scale correctly regardless of key
+/// spacing.
+fn hermite(v0: f32, v1: f32, m0: f32, m1: f32, s: f32, span_secs: f32) -> f32 {
+    let s2 = s * s;
+    let s3 = s2 * s;
+    let h00 = 2.0 * s3 - 3.0 * s2 + 1.0;
+    let h10 = s3 - 2.0 * s2 + s;
+    let h01 = -2.0 * s3 + 3.0 * s2;
+    let h11 = s3 - s2;
+    h00 * v0 + h10 * span_secs * m0 + h01 * v1 + h11 * span_secs * m1
+}
+
+define_object_subtype! {
+    /// `AnimationCurveNode` node handle.
+    AnimationCurveNodeHandle: ObjectHandle
+}
+
+impl<'a> AnimationCurveNodeHandle<'a> {
+    /// Returns the component curve connected with the given label (e.g. `"d|X"`).
+    pub fn curve(&self, label: &str) -> Option<AnimationCurveHandle<'a>> {
+        self.source_objects()
+            .filter(|obj| obj.label() == Some(label))
+            .filter_map(|obj| obj.object_handle())
+            .filter_map(|obj| match obj.get_typed() {
+                TypedObjectHandle::AnimationCurve(o) => Some(o),
+                _ => None,
+            })
+            .next()
+    }
+
+    /// Evaluates the `X`/`Y`/`Z` component curves at the given time.
+    ///
+    /// A component with no connected curve falls back to the static default
+    /// stored in the node's `d|{X,Y,Z}` property (and to `0.0` if that is also
+    /// absent), so partially-animated channels keep their unanimated values
+    /// rather than collapsing to zero.
+    pub fn evaluate_vec3(&self, time: FbxTime) -> Result<[f32; 3]> {
+        let mut out = [0.0; 3];
+        for (i, label) in ["d|X", "d|Y", "d|Z"].iter().enumerate() {
+            out[i] = match self.curve(label) {
+                Some(curve) => curve.evaluate(time)?,
+                None => self.default_component(label).unwrap_or(0.0),
+            };
+        }
+        Ok(out)
+    }
+
+    /// Returns the static default value stored in the given `d|{X,Y,Z}` property.
+    fn default_component(&self, name: &str) -> Option<f32> {
+        self.direct_properties()
+            .and_then(|props| props.get_f64(name))
+            .map(|v| v as f32)
+    }
+}
+
+define_object_subtype! {
+    /// `AnimationLayer` node handle.
+    AnimationLayerHandle: ObjectHandle
+}
+
+impl<'a> AnimationLayerHandle<'a> {
+    /// Returns an iterator over the curve nodes aggregated by this layer.
+    pub fn curve_nodes(&self) -> impl Iterator<Item = AnimationCurveNodeHandle<'a>> + 'a {
+        self.source_objects()
+            .filter_map(|obj| obj.object_handle())
+            .filter_map(|obj| match obj.get_typed() {
+                TypedObjectHandle::AnimationCurveNode(o) => Some(o),
+                _ => None,
+            })
+    }
+}
+
+define_object_subtype! {
+    /// `AnimationStack` node handle.
+    AnimationStackHandle: ObjectHandle
+}
+
+impl<'a> AnimationStackHandle<'a> {
+    /// Returns an iterator over the layers aggregated by this stack.
+    pub fn layers(&self) -> impl Iterator<Item = AnimationLayerHandle<'a>> + 'a {
+        self.source_objects()
+            .filter_map(|obj| obj.object_handle())
+            .filter_map(|obj| match obj.get_typed() {
+                TypedObjectHandle::AnimationLayer(o) => Some(o),
+                _ => None,
+            })
+    }
+
+    /// Returns properties.
+    pub fn properties(&self) -> AnimationStackProperties<'a> {
+        AnimationStackProperties {
+            properties: self.properties_by_native_typename("FbxAnimStack"),
+        }
+    }
+}
+
+/// Proxy type to animation stack properties.
+#[derive(Debug, Clone, Copy)]
+pub struct AnimationStackProperties<'a> {
+    /// Properties.
+    properties: ObjectProperties<'a>,
+}
+
+impl<'a> AnimationStackProperties<'a> {
+    /// Returns the local start time of the take.
+    pub fn local_start(&self) -> Result<Option<FbxTime>> {
+        self.local_time("LocalStart")
+    }
+
+    /// Returns the local stop time of the take.
+    pub fn local_stop(&self) -> Result<Option<FbxTime>> {
+        self.local_time("LocalStop")
+    }
+
+    /// Loads a named time property as an [`FbxTime`].
+    fn local_time(&self, name: &str) -> Result<Option<FbxTime>> {
+        self.properties
+            .get_property(name)
+            .map(|p| p.load_value(PrimitiveLoader::<i64>::new()).map(FbxTime::from_raw))
+            .transpose()
+            .map_err(Into::into)
+    }
+}
+
+impl<'a> std::ops::Deref for AnimationStackProperties<'a> {
+    type Target = ObjectProperties<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.properties
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LINEAR: i32 = 0x0000_0004;
+
+    #[test]
+    fn fbx_time_roundtrips_seconds() {
+        assert_eq!(FbxTime::from_seconds(1.0).raw(), FBX_TIME_UNITS_PER_SECOND);
+        assert!((FbxTime(FBX_TIME_UNITS_PER_SECOND).as_seconds() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn attr_entry_follows_ref_counts() {
+        let ref_counts = [2, 1];
+        assert_eq!(attr_entry(&ref_counts, 0), 0);
+        assert_eq!(attr_entry(&ref_counts, 1), 0);
+        assert_eq!(attr_entry(&ref_counts, 2), 1);
+        // Past the end clamps to the last entry.
+        assert_eq!(attr_entry(&ref_counts, 9), 1);
+        // No ref counts means a single shared entry.
+        assert_eq!(attr_entry(&[], 5), 0);
+    }
+
+    #[test]
+    fn evaluate_clamps_outside_range() {
+        let times = [0, 100];
+        let values = [1.0, 3.0];
+        assert_eq!(evaluate_keys(&times, &values, &[LINEAR], &[], &[], -10), 1.0);
+        assert_eq!(evaluate_keys(&times, &values, &[LINEAR], &[], &[], 200), 3.0);
+    }
+
+    #[test]
+    fn evaluate_linear_midpoint() {
+        let got = evaluate_keys(&[0, 100], &[1.0, 3.0], &[LINEAR], &[], &[], 50);
+        assert!((got - 2.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn evaluate_constant_holds_left_value() {
+        let got = evaluate_keys(&[0, 100], &[1.0, 3.0], &[KEY_INTERP_CONSTANT], &[], &[], 50);
+        assert_eq!(got, 1.0);
+    }
+
+    #[test]
+    fn evaluate_cubic_uses_hermite() {
+        let span = FbxTime::from_seconds(1.0).raw();
+        // Flat tangents reduce the Hermite segment to a smoothstep: 0.5 at the midpoint.
+        let got = evaluate_keys(
+            &[0, span],
+            &[0.0, 1.0],
+            &[KEY_INTERP_CUBIC],
+            &[0.0, 0.0],
+            &[],
+            span / 2,
+        );
+        assert!((got - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn hermite_endpoints_and_midpoint() {
+        assert_eq!(hermite(2.0, 5.0, 0.0, 0.0, 0.0, 1.0), 2.0);
+        assert_eq!(hermite(2.0, 5.0, 0.0, 0.0, 1.0, 1.0), 5.0);
+        assert!((hermite(0.0, 1.0, 0.0, 0.0, 0.5, 1.0) - 0.5).abs() < 1e-6);
+    }
+}
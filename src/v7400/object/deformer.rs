@@ -1,10 +1,12 @@
 //! Objects with `Deformer` class.
 
+pub mod cluster;
 pub mod skin;
 
 use crate::v7400::object::{ObjectHandle, ObjectId, ObjectNodeId, ObjectSubtypeHandle};
 use crate::v7400::Result;
 
+pub use self::cluster::{SubDeformerClusterHandle, SubDeformerClusterNodeId};
 pub use self::skin::{DeformerSkinHandle, DeformerSkinNodeId};
 
 /// Node ID for a deformer object.
@@ -67,3 +69,11 @@ pub enum DeformerSubclass {
     /// `Skin` subclass.
     Skin,
 }
+
+/// Subclass of a sub-deformer known to the fbxcel-dom crate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SubDeformerSubclass {
+    /// `Cluster` subclass.
+    Cluster,
+}
@@ -0,0 +1,184 @@
+//! `SubDeformer` objects with `Cluster` subclass.
+
+use fbxcel::low::v7400::AttributeValue;
+
+use crate::v7400::object::deformer::DeformerSkinHandle;
+use crate::v7400::object::model::ModelHandle;
+use crate::v7400::object::{ObjectHandle, ObjectId, ObjectNodeId, ObjectSubtypeHandle};
+use crate::v7400::Result;
+
+/// Node ID for a cluster sub-deformer object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SubDeformerClusterNodeId(ObjectNodeId);
+
+/// Object handle for a cluster sub-deformer object.
+#[derive(Debug, Clone, Copy)]
+pub struct SubDeformerClusterHandle<'a> {
+    /// Object handle.
+    object: ObjectHandle<'a>,
+}
+
+impl<'a> SubDeformerClusterHandle<'a> {
+    /// Returns the object ID.
+    #[inline]
+    #[must_use]
+    pub fn object_id(&self) -> ObjectId {
+        self.object.id()
+    }
+
+    /// Returns the affected control-point indices.
+    pub fn indexes(&self) -> Result<Vec<i32>> {
+        match self.object.array_child("Indexes")? {
+            Some(AttributeValue::ArrI32(arr)) => Ok(arr.to_vec()),
+            Some(attr) => Err(error!("expected i32 array for `Indexes` but got {:?}", attr)),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Returns the per-index skinning weights.
+    pub fn weights(&self) -> Result<Vec<f64>> {
+        match self.object.array_child("Weights")? {
+            Some(AttributeValue::ArrF64(arr)) => Ok(arr.to_vec()),
+            Some(attr) => Err(error!("expected f64 array for `Weights` but got {:?}", attr)),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Returns the cluster `Transform` 4×4 matrix, in row-major order.
+    pub fn transform(&self) -> Result<Option<[[f64; 4]; 4]>> {
+        self.matrix_child("Transform")
+    }
+
+    /// Returns the cluster `TransformLink` 4×4 matrix, in row-major order.
+    pub fn transform_link(&self) -> Result<Option<[[f64; 4]; 4]>> {
+        self.matrix_child("TransformLink")
+    }
+
+    /// Returns the bone (`Model`) this cluster is linked to, if any.
+    ///
+    /// The bone is resolved through the cluster's object connections.
+    pub fn bone(&self) -> Option<ModelHandle<'a>> {
+        self.object
+            .destination_objects()
+            .chain(self.object.source_objects())
+            .filter_map(|conn| conn.object_handle())
+            .find_map(|obj| ModelHandle::from_object(&obj).ok())
+    }
+
+    /// Reads a 16-element `f64` array child into a row-major 4×4 matrix.
+    fn matrix_child(&self, name: &str) -> Result<Option<[[f64; 4]; 4]>> {
+        let values = match self.object.array_child(name)? {
+            Some(AttributeValue::ArrF64(arr)) => arr,
+            Some(attr) => return Err(error!("expected f64 array for `{}` but got {:?}", name, attr)),
+            None => return Ok(None),
+        };
+        parse_matrix4(name, &values).map(Some)
+    }
+}
+
+/// Parses 16 `f64` values into a row-major 4×4 matrix.
+fn parse_matrix4(name: &str, values: &[f64]) -> Result<[[f64; 4]; 4]> {
+    if values.len() != 16 {
+        return Err(error!(
+            "expected 16 elements for `{}` matrix but got {}",
+            name,
+            values.len()
+        ));
+    }
+    let mut m = [[0.0; 4]; 4];
+    for (i, value) in values.iter().enumerate() {
+        m[i / 4][i % 4] = *value;
+    }
+    Ok(m)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_matrix4_is_row_major() {
+        let values: Vec<f64> = (0..16).map(|i| i as f64).collect();
+        let m = parse_matrix4("Transform", &values).expect("16 elements");
+        assert_eq!(m[0], [0.0, 1.0, 2.0, 3.0]);
+        assert_eq!(m[3], [12.0, 13.0, 14.0, 15.0]);
+    }
+
+    #[test]
+    fn parse_matrix4_rejects_wrong_arity() {
+        assert!(parse_matrix4("Transform", &[0.0; 9]).is_err());
+    }
+}
+
+impl<'a> ObjectSubtypeHandle<'a> for SubDeformerClusterHandle<'a> {
+    type NodeId = SubDeformerClusterNodeId;
+
+    fn from_object(object: &ObjectHandle<'a>) -> Result<Self> {
+        let class = object.class();
+        if class != "SubDeformer" {
+            return Err(error!(
+                "not a cluster object: expected \"SubDeformer\" class but got {:?} class",
+                class
+            ));
+        }
+        let subclass = object.subclass();
+        if subclass != "Cluster" {
+            return Err(error!(
+                "not a cluster object: expected \"Cluster\" subclass but got {:?} subclass",
+                subclass
+            ));
+        }
+
+        Ok(Self { object: *object })
+    }
+
+    #[inline]
+    fn as_object(&self) -> &ObjectHandle<'a> {
+        &self.object
+    }
+
+    #[inline]
+    fn node_id(&self) -> Self::NodeId {
+        SubDeformerClusterNodeId(self.object.node_id())
+    }
+}
+
+impl<'a> AsRef<ObjectHandle<'a>> for SubDeformerClusterHandle<'a> {
+    #[inline]
+    fn as_ref(&self) -> &ObjectHandle<'a> {
+        self.as_object()
+    }
+}
+
+impl<'a> ObjectHandle<'a> {
+    /// Returns the first attribute of the single child node with the given name.
+    pub(crate) fn array_child(&self, name: &str) -> Result<Option<AttributeValue>> {
+        Ok(self
+            .node()
+            .children_by_name(name)
+            .next()
+            .and_then(|node| node.attributes().first().cloned()))
+    }
+}
+
+impl<'a> DeformerSkinHandle<'a> {
+    /// Returns the skin's `SkinningType` property value, if present.
+    pub fn skinning_type(&self) -> Option<&'a str> {
+        self.as_object()
+            .direct_properties()
+            .and_then(|props| props.get_property("SkinningType"))
+            .and_then(|prop| prop.value_part().first())
+            .and_then(|attr| match attr {
+                AttributeValue::String(s) => Some(s.as_str()),
+                _ => None,
+            })
+    }
+
+    /// Returns an iterator over the skin's cluster sub-deformers.
+    pub fn clusters(&self) -> impl Iterator<Item = SubDeformerClusterHandle<'a>> + 'a {
+        self.as_object()
+            .source_objects()
+            .filter_map(|conn| conn.object_handle())
+            .filter_map(|obj| SubDeformerClusterHandle::from_object(&obj).ok())
+    }
+}
@@ -0,0 +1,328 @@
+//! `GlobalSettings` and coordinate-system conversion.
+
+use failure::{format_err, Error, ResultExt};
+
+use crate::v7400::{
+    object::property::{loaders::PrimitiveLoader, ObjectProperties},
+    Document,
+};
+
+/// A signed coordinate axis, as encoded by an `*Axis`/`*AxisSign` property pair.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SignedAxis {
+    /// Axis index: `0` = X, `1` = Y, `2` = Z.
+    axis: u8,
+    /// Axis direction: `true` for positive, `false` for negative.
+    positive: bool,
+}
+
+impl SignedAxis {
+    /// Creates a signed axis from an axis index and a sign.
+    ///
+    /// The axis index follows the FBX convention (`0`/`1`/`2` → X/Y/Z) and the
+    /// sign is positive for any non-negative value.
+    fn new(axis: i32, sign: i32) -> Result<Self, Error> {
+        let axis = u8::try_from(axis)
+            .ok()
+            .filter(|a| *a < 3)
+            .ok_or_else(|| format_err!("invalid axis index {}", axis))?;
+        Ok(Self {
+            axis,
+            positive: sign >= 0,
+        })
+    }
+
+    /// Returns the axis as a unit vector in the world basis.
+    fn unit_vector(&self) -> [f64; 3] {
+        let mut v = [0.0; 3];
+        v[usize::from(self.axis)] = if self.positive { 1.0 } else { -1.0 };
+        v
+    }
+}
+
+/// An orthonormal coordinate frame described by its up, front, and coord axes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CoordinateSystem {
+    /// Up axis.
+    pub up: SignedAxis,
+    /// Front axis.
+    pub front: SignedAxis,
+    /// Coord (right) axis.
+    pub coord: SignedAxis,
+}
+
+impl CoordinateSystem {
+    /// Returns the 3×3 basis matrix whose columns are the coord, up, and front
+    /// axes expressed in the world basis.
+    fn basis(&self) -> [[f64; 3]; 3] {
+        let coord = self.coord.unit_vector();
+        let up = self.up.unit_vector();
+        let front = self.front.unit_vector();
+        // Column `j` of the returned matrix is the `j`-th frame axis.
+        let mut m = [[0.0; 3]; 3];
+        for row in 0..3 {
+            m[row][0] = coord[row];
+            m[row][1] = up[row];
+            m[row][2] = front[row];
+        }
+        m
+    }
+
+    /// Returns the basis-change matrix mapping this coordinate system into
+    /// `target`.
+    ///
+    /// Both frames are orthonormal sign-permutation bases, so the conversion is
+    /// `target * self⁻¹`, and the inverse of such a basis is its transpose.
+    pub fn conversion_matrix(&self, target: &CoordinateSystem) -> [[f64; 3]; 3] {
+        mul3(&target.basis(), &transpose3(&self.basis()))
+    }
+
+    /// Returns the 4×4 basis-change matrix mapping this coordinate system into
+    /// `target`, with `scale` applied uniformly to the linear part.
+    ///
+    /// `scale` is typically [`GlobalSettings::unit_scale_to_meters`] so that
+    /// linear distances and translations are converted alongside the axes.
+    pub fn conversion_matrix4(&self, target: &CoordinateSystem, scale: f64) -> [[f64; 4]; 4] {
+        let linear = self.conversion_matrix(target);
+        let mut m = [[0.0; 4]; 4];
+        for row in 0..3 {
+            for col in 0..3 {
+                m[row][col] = linear[row][col] * scale;
+            }
+        }
+        m[3][3] = 1.0;
+        m
+    }
+}
+
+/// Multiplies two 3×3 matrices.
+fn mul3(a: &[[f64; 3]; 3], b: &[[f64; 3]; 3]) -> [[f64; 3]; 3] {
+    let mut m = [[0.0; 3]; 3];
+    for row in 0..3 {
+        for col in 0..3 {
+            m[row][col] = (0..3).map(|k| a[row][k] * b[k][col]).sum();
+        }
+    }
+    m
+}
+
+/// Transposes a 3×3 matrix.
+fn transpose3(a: &[[f64; 3]; 3]) -> [[f64; 3]; 3] {
+    let mut m = [[0.0; 3]; 3];
+    for row in 0..3 {
+        for col in 0..3 {
+            m[row][col] = a[col][row];
+        }
+    }
+    m
+}
+
+/// Proxy type to the document's `GlobalSettings` properties.
+#[derive(Debug, Clone, Copy)]
+pub struct GlobalSettings<'a> {
+    /// Properties.
+    properties: ObjectProperties<'a>,
+}
+
+impl<'a> GlobalSettings<'a> {
+    /// Creates a new handle from the given properties.
+    pub(crate) fn new(properties: ObjectProperties<'a>) -> Self {
+        Self { properties }
+    }
+
+    /// Returns the source coordinate system declared by the file.
+    pub fn coordinate_system(&self) -> Result<CoordinateSystem, Error> {
+        Ok(CoordinateSystem {
+            up: SignedAxis::new(self.up_axis_or_default()?, self.up_axis_sign_or_default()?)?,
+            front: SignedAxis::new(
+                self.front_axis_or_default()?,
+                self.front_axis_sign_or_default()?,
+            )?,
+            coord: SignedAxis::new(
+                self.coord_axis_or_default()?,
+                self.coord_axis_sign_or_default()?,
+            )?,
+        })
+    }
+
+    /// Returns the factor converting FBX linear units (centimeters) to meters.
+    pub fn unit_scale_to_meters(&self) -> Result<f64, Error> {
+        Ok(self.unit_scale_factor_or_default()? / 100.0)
+    }
+
+    /// Returns the basis-change matrix mapping this file's coordinate system
+    /// into `target`, scaling the linear part so translations come out in
+    /// meters.
+    pub fn conversion_matrix4(&self, target: &CoordinateSystem) -> Result<[[f64; 4]; 4], Error> {
+        let source = self.coordinate_system()?;
+        Ok(source.conversion_matrix4(target, self.unit_scale_to_meters()?))
+    }
+
+    impl_prop_proxy_getters! {
+        /// Returns the up axis index.
+        up_axis -> i32 {
+            name = "UpAxis",
+            loader = PrimitiveLoader::<i32>::new(),
+            description = "up axis",
+            default: {
+                /// Returns the up axis index, defaulting to Y.
+                up_axis_or_default = 1
+            }
+        }
+
+        /// Returns the up axis sign.
+        up_axis_sign -> i32 {
+            name = "UpAxisSign",
+            loader = PrimitiveLoader::<i32>::new(),
+            description = "up axis sign",
+            default: {
+                /// Returns the up axis sign, defaulting to positive.
+                up_axis_sign_or_default = 1
+            }
+        }
+
+        /// Returns the front axis index.
+        front_axis -> i32 {
+            name = "FrontAxis",
+            loader = PrimitiveLoader::<i32>::new(),
+            description = "front axis",
+            default: {
+                /// Returns the front axis index, defaulting to Z.
+                front_axis_or_default = 2
+            }
+        }
+
+        /// Returns the front axis sign.
+        front_axis_sign -> i32 {
+            name = "FrontAxisSign",
+            loader = PrimitiveLoader::<i32>::new(),
+            description = "front axis sign",
+            default: {
+                /// Returns the front axis sign, defaulting to positive.
+                front_axis_sign_or_default = 1
+            }
+        }
+
+        /// Returns the coord axis index.
+        coord_axis -> i32 {
+            name = "CoordAxis",
+            loader = PrimitiveLoader::<i32>::new(),
+            description = "coord axis",
+            default: {
+                /// Returns the coord axis index, defaulting to X.
+                coord_axis_or_default = 0
+            }
+        }
+
+        /// Returns the coord axis sign.
+        coord_axis_sign -> i32 {
+            name = "CoordAxisSign",
+            loader = PrimitiveLoader::<i32>::new(),
+            description = "coord axis sign",
+            default: {
+                /// Returns the coord axis sign, defaulting to positive.
+                coord_axis_sign_or_default = 1
+            }
+        }
+
+        /// Returns the unit scale factor.
+        unit_scale_factor -> f64 {
+            name = "UnitScaleFactor",
+            loader = PrimitiveLoader::<f64>::new(),
+            description = "unit scale factor",
+            default: {
+                /// Returns the unit scale factor, defaulting to centimeters.
+                unit_scale_factor_or_default = 1.0
+            }
+        }
+
+        /// Returns the original unit scale factor.
+        original_unit_scale_factor -> f64 {
+            name = "OriginalUnitScaleFactor",
+            loader = PrimitiveLoader::<f64>::new(),
+            description = "original unit scale factor",
+            default: {
+                /// Returns the original unit scale factor, defaulting to centimeters.
+                original_unit_scale_factor_or_default = 1.0
+            }
+        }
+    }
+}
+
+impl<'a> std::ops::Deref for GlobalSettings<'a> {
+    type Target = ObjectProperties<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.properties
+    }
+}
+
+impl Document {
+    /// Returns the document's global settings, if present.
+    pub fn global_settings(&self) -> Option<GlobalSettings<'_>> {
+        self.global_settings_properties().map(GlobalSettings::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn signed(axis: i32, sign: i32) -> SignedAxis {
+        SignedAxis::new(axis, sign).expect("valid axis")
+    }
+
+    fn apply(m: &[[f64; 3]; 3], v: [f64; 3]) -> [f64; 3] {
+        let mut out = [0.0; 3];
+        for row in 0..3 {
+            out[row] = (0..3).map(|col| m[row][col] * v[col]).sum();
+        }
+        out
+    }
+
+    /// A Z-up (X-right, Y-front) frame, as used by e.g. 3ds Max.
+    fn z_up() -> CoordinateSystem {
+        CoordinateSystem {
+            up: signed(2, 1),
+            front: signed(1, 1),
+            coord: signed(0, 1),
+        }
+    }
+
+    /// The default FBX Y-up (X-right, Z-front) frame.
+    fn y_up() -> CoordinateSystem {
+        CoordinateSystem {
+            up: signed(1, 1),
+            front: signed(2, 1),
+            coord: signed(0, 1),
+        }
+    }
+
+    #[test]
+    fn z_up_to_y_up_swaps_up_and_front() {
+        let m = z_up().conversion_matrix(&y_up());
+        // The source up axis (+Z) maps to the target up axis (+Y).
+        assert_eq!(apply(&m, [0.0, 0.0, 1.0]), [0.0, 1.0, 0.0]);
+        // The source front axis (+Y) maps to the target front axis (+Z).
+        assert_eq!(apply(&m, [0.0, 1.0, 0.0]), [0.0, 0.0, 1.0]);
+        // The shared right axis (+X) is unchanged.
+        assert_eq!(apply(&m, [1.0, 0.0, 0.0]), [1.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn identity_conversion_is_identity() {
+        let m = y_up().conversion_matrix(&y_up());
+        for (row, expected) in m.iter().zip(&[[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]) {
+            assert_eq!(row, expected);
+        }
+    }
+
+    #[test]
+    fn conversion_matrix4_scales_linear_part() {
+        let m = z_up().conversion_matrix4(&y_up(), 0.01);
+        // +Z up scaled into +Y up at 1cm = 0.01m.
+        assert_eq!(m[1][2], 0.01);
+        assert_eq!(m[3][3], 1.0);
+        assert_eq!(m[3], [0.0, 0.0, 0.0, 1.0]);
+    }
+}
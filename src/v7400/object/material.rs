@@ -0,0 +1,173 @@
+//! `Material` object.
+
+use failure::{format_err, Error, ResultExt};
+use rgb::RGB;
+
+use crate::v7400::object::{
+    property::{
+        loaders::{PrimitiveLoader, RgbLoader},
+        ObjectProperties,
+    },
+    texture::TextureHandle,
+    ObjectHandle, TypedObjectHandle,
+};
+
+define_object_subtype! {
+    /// `Material` node handle.
+    MaterialHandle: ObjectHandle
+}
+
+impl<'a> MaterialHandle<'a> {
+    /// Returns the texture bound to the given slot, if any.
+    ///
+    /// Source objects are filtered by their connection label (e.g.
+    /// `"DiffuseColor"` or `"NormalMap"`), and the first connected texture is
+    /// returned.
+    pub fn load_texture(&self, slot: &str) -> Option<TextureHandle<'a>> {
+        self.source_objects()
+            .filter(|obj| obj.label() == Some(slot))
+            .filter_map(|obj| obj.object_handle())
+            .filter_map(|obj| match obj.get_typed() {
+                TypedObjectHandle::Texture(o) => Some(o),
+                _ => None,
+            })
+            .next()
+    }
+
+    /// Returns properties.
+    ///
+    /// The property template is selected from the material's subclass so that
+    /// both `Lambert` and `Phong` materials resolve their template defaults.
+    pub fn properties(&self) -> MaterialProperties<'a> {
+        let native_typename = match self.subclass() {
+            "Lambert" => "FbxSurfaceLambert",
+            _ => "FbxSurfacePhong",
+        };
+        MaterialProperties {
+            properties: self.properties_by_native_typename(native_typename),
+        }
+    }
+}
+
+/// Proxy type to material properties.
+#[derive(Debug, Clone, Copy)]
+pub struct MaterialProperties<'a> {
+    /// Properties.
+    properties: ObjectProperties<'a>,
+}
+
+impl<'a> MaterialProperties<'a> {
+    impl_prop_proxy_getters! {
+        /// Returns diffuse color.
+        diffuse_color -> RGB<f64> {
+            name = "DiffuseColor",
+            loader = RgbLoader::<RGB<f64>>::new(),
+            description = "diffuse color",
+            default: {
+                /// Returns diffuse color.
+                ///
+                /// Returns default if the value is not set.
+                diffuse_color_or_default = RGB::new(0.0, 0.0, 0.0)
+            }
+        }
+
+        /// Returns diffuse factor.
+        diffuse_factor -> f64 {
+            name = "DiffuseFactor",
+            loader = PrimitiveLoader::<f64>::new(),
+            description = "diffuse factor",
+            default: {
+                /// Returns diffuse factor.
+                ///
+                /// Returns default if the value is not set.
+                diffuse_factor_or_default = 1.0
+            }
+        }
+
+        /// Returns specular color.
+        specular_color -> RGB<f64> {
+            name = "SpecularColor",
+            loader = RgbLoader::<RGB<f64>>::new(),
+            description = "specular color",
+            default: {
+                /// Returns specular color.
+                ///
+                /// Returns default if the value is not set.
+                specular_color_or_default = RGB::new(0.0, 0.0, 0.0)
+            }
+        }
+
+        /// Returns shininess.
+        shininess -> f64 {
+            name = "Shininess",
+            loader = PrimitiveLoader::<f64>::new(),
+            description = "shininess",
+            default: {
+                /// Returns shininess.
+                ///
+                /// Returns default if the value is not set.
+                shininess_or_default = 0.0
+            }
+        }
+
+        /// Returns emissive color.
+        emissive_color -> RGB<f64> {
+            name = "EmissiveColor",
+            loader = RgbLoader::<RGB<f64>>::new(),
+            description = "emissive color",
+            default: {
+                /// Returns emissive color.
+                ///
+                /// Returns default if the value is not set.
+                emissive_color_or_default = RGB::new(0.0, 0.0, 0.0)
+            }
+        }
+
+        /// Returns emissive factor.
+        emissive_factor -> f64 {
+            name = "EmissiveFactor",
+            loader = PrimitiveLoader::<f64>::new(),
+            description = "emissive factor",
+            default: {
+                /// Returns emissive factor.
+                ///
+                /// Returns default if the value is not set.
+                emissive_factor_or_default = 1.0
+            }
+        }
+
+        /// Returns transparency factor.
+        transparency_factor -> f64 {
+            name = "TransparencyFactor",
+            loader = PrimitiveLoader::<f64>::new(),
+            description = "transparency factor",
+            default: {
+                /// Returns transparency factor.
+                ///
+                /// Returns default if the value is not set.
+                transparency_factor_or_default = 0.0
+            }
+        }
+
+        /// Returns reflection factor.
+        reflection_factor -> f64 {
+            name = "ReflectionFactor",
+            loader = PrimitiveLoader::<f64>::new(),
+            description = "reflection factor",
+            default: {
+                /// Returns reflection factor.
+                ///
+                /// Returns default if the value is not set.
+                reflection_factor_or_default = 0.0
+            }
+        }
+    }
+}
+
+impl<'a> std::ops::Deref for MaterialProperties<'a> {
+    type Target = ObjectProperties<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.properties
+    }
+}
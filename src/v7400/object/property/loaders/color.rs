@@ -0,0 +1,94 @@
+//! Loader for color-valued properties.
+
+use std::marker::PhantomData;
+
+use failure::{format_err, Error};
+use rgb::{RGB, RGBA};
+
+use crate::v7400::object::property::{
+    loaders::vector::load_f64_components, LoadProperty, PropertyHandle,
+};
+
+/// A color that can be built from widened `f64` components.
+///
+/// Implemented for [`rgb::RGB<f64>`] (3 components) and [`rgb::RGBA<f64>`]
+/// (4 components) so that [`RgbLoader`] can yield either from a single
+/// FBX color property.
+pub trait ColorComponents: Sized {
+    /// Number of components the color expects.
+    const ARITY: usize;
+
+    /// Builds the color from exactly [`ARITY`][`Self::ARITY`] components.
+    fn from_components(components: &[f64]) -> Self;
+}
+
+impl ColorComponents for RGB<f64> {
+    const ARITY: usize = 3;
+
+    fn from_components(components: &[f64]) -> Self {
+        RGB::new(components[0], components[1], components[2])
+    }
+}
+
+impl ColorComponents for RGBA<f64> {
+    const ARITY: usize = 4;
+
+    fn from_components(components: &[f64]) -> Self {
+        RGBA::new(components[0], components[1], components[2], components[3])
+    }
+}
+
+/// Loader producing an [`rgb`] color from a color-valued FBX property.
+///
+/// FBX materials store colors such as `DiffuseColor` and `SpecularColor` as
+/// color doubles. The element type is widened to `f64` and the arity is checked
+/// against the target color type.
+pub struct RgbLoader<T>(PhantomData<fn() -> T>);
+
+impl<T> RgbLoader<T> {
+    /// Creates a new loader.
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T> Default for RgbLoader<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> std::fmt::Debug for RgbLoader<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RgbLoader").finish()
+    }
+}
+
+impl<T> Clone for RgbLoader<T> {
+    fn clone(&self) -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Copy for RgbLoader<T> {}
+
+impl<'a, T: ColorComponents> LoadProperty<'a> for RgbLoader<T> {
+    type Value = T;
+    type Error = Error;
+
+    fn expecting(&self) -> String {
+        format!("color with {} components", T::ARITY)
+    }
+
+    fn load(self, node: &PropertyHandle<'a>) -> Result<Self::Value, Self::Error> {
+        let components = load_f64_components(node)?;
+        if components.len() != T::ARITY {
+            return Err(format_err!(
+                "expected {} color components, but got {}",
+                T::ARITY,
+                components.len()
+            ));
+        }
+        Ok(T::from_components(&components))
+    }
+}
@@ -0,0 +1,189 @@
+//! Loaders for fixed-size numeric vector properties.
+
+use std::marker::PhantomData;
+
+use failure::{format_err, Error};
+use fbxcel::low::v7400::AttributeValue;
+
+use crate::v7400::object::property::{LoadProperty, PropertyHandle};
+
+/// Reads a single property value attribute as an `f64`.
+///
+/// Integer and boolean attributes are widened, so that loaders can accept the
+/// slightly different numeric encodings various exporters emit.
+fn attr_as_f64(attr: &AttributeValue) -> Option<f64> {
+    match attr {
+        AttributeValue::Bool(v) => Some(if *v { 1.0 } else { 0.0 }),
+        AttributeValue::I16(v) => Some(f64::from(*v)),
+        AttributeValue::I32(v) => Some(f64::from(*v)),
+        AttributeValue::I64(v) => Some(*v as f64),
+        AttributeValue::F32(v) => Some(f64::from(*v)),
+        AttributeValue::F64(v) => Some(*v),
+        _ => None,
+    }
+}
+
+/// Collects the numeric components of a property into a `Vec<f64>`.
+///
+/// Components may be stored either as a single packed array attribute (`ArrF32`
+/// / `ArrF64`) or as a run of scalar attributes, as with `Lcl Translation`;
+/// both layouts are accepted.
+pub(crate) fn load_f64_components<'a>(node: &PropertyHandle<'a>) -> Result<Vec<f64>, Error> {
+    match node.value_part() {
+        [AttributeValue::ArrF64(arr)] => Ok(arr.to_vec()),
+        [AttributeValue::ArrF32(arr)] => Ok(arr.iter().map(|v| f64::from(*v)).collect()),
+        values => values
+            .iter()
+            .enumerate()
+            .map(|(i, attr)| {
+                attr_as_f64(attr).ok_or_else(|| {
+                    format_err!("expected numeric component at index {}, but got {:?}", i, attr)
+                })
+            })
+            .collect(),
+    }
+}
+
+/// Loads exactly `$arity` `f64` components into a `[f64; $arity]`.
+macro_rules! define_f64_arr_loader {
+    ($(#[$meta:meta])* $ty:ident, $arity:expr) => {
+        $(#[$meta])*
+        #[derive(Debug, Default, Clone, Copy)]
+        pub struct $ty(());
+
+        impl $ty {
+            /// Creates a new loader.
+            pub fn new() -> Self {
+                Self::default()
+            }
+        }
+
+        impl<'a> LoadProperty<'a> for $ty {
+            type Value = [f64; $arity];
+            type Error = Error;
+
+            fn expecting(&self) -> String {
+                format!("array of {} f64 values", $arity)
+            }
+
+            fn load(self, node: &PropertyHandle<'a>) -> Result<Self::Value, Self::Error> {
+                let components = load_f64_components(node)?;
+                if components.len() != $arity {
+                    return Err(format_err!(
+                        "expected {} components, but got {}",
+                        $arity,
+                        components.len()
+                    ));
+                }
+                let mut value = [0.0; $arity];
+                value.copy_from_slice(&components);
+                Ok(value)
+            }
+        }
+    };
+}
+
+define_f64_arr_loader! {
+    /// Loader for 2-component `f64` array properties.
+    F64Arr2Loader, 2
+}
+
+define_f64_arr_loader! {
+    /// Loader for 4-component `f64` array properties.
+    F64Arr4Loader, 4
+}
+
+/// A fixed-arity vector that can be built from widened `f64` components.
+///
+/// Implemented for the [`mint`] vector types so that [`MintLoader`] can yield
+/// them directly, letting downstream engines consume FBX vector properties
+/// without hand-rolling per-arity loaders.
+pub trait MintVector: Sized {
+    /// Number of components the vector expects.
+    const ARITY: usize;
+
+    /// Builds the vector from exactly [`ARITY`][`Self::ARITY`] components.
+    fn from_components(components: &[f64]) -> Self;
+}
+
+macro_rules! impl_mint_vector {
+    ($($vec:ident { $($field:ident = $idx:expr),* } = $arity:expr;)*) => {
+        $(
+            impl MintVector for mint::$vec<f64> {
+                const ARITY: usize = $arity;
+
+                fn from_components(components: &[f64]) -> Self {
+                    mint::$vec { $($field: components[$idx]),* }
+                }
+            }
+
+            impl MintVector for mint::$vec<f32> {
+                const ARITY: usize = $arity;
+
+                fn from_components(components: &[f64]) -> Self {
+                    mint::$vec { $($field: components[$idx] as f32),* }
+                }
+            }
+        )*
+    };
+}
+
+impl_mint_vector! {
+    Vector2 { x = 0, y = 1 } = 2;
+    Vector3 { x = 0, y = 1, z = 2 } = 3;
+    Vector4 { x = 0, y = 1, z = 2, w = 3 } = 4;
+}
+
+/// Loader producing a [`mint`] vector from a numeric FBX array property.
+///
+/// The element type is widened to `f64` (or narrowed to `f32` for the `f32`
+/// variants) and the arity is checked against the target vector.
+pub struct MintLoader<T>(PhantomData<fn() -> T>);
+
+impl<T> MintLoader<T> {
+    /// Creates a new loader.
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T> Default for MintLoader<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> std::fmt::Debug for MintLoader<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MintLoader").finish()
+    }
+}
+
+impl<T> Clone for MintLoader<T> {
+    fn clone(&self) -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Copy for MintLoader<T> {}
+
+impl<'a, T: MintVector> LoadProperty<'a> for MintLoader<T> {
+    type Value = T;
+    type Error = Error;
+
+    fn expecting(&self) -> String {
+        format!("array of {} numeric components", T::ARITY)
+    }
+
+    fn load(self, node: &PropertyHandle<'a>) -> Result<Self::Value, Self::Error> {
+        let components = load_f64_components(node)?;
+        if components.len() != T::ARITY {
+            return Err(format_err!(
+                "expected {} components, but got {}",
+                T::ARITY,
+                components.len()
+            ));
+        }
+        Ok(T::from_components(&components))
+    }
+}
@@ -0,0 +1,57 @@
+//! Untyped named-property accessors.
+
+use crate::v7400::object::property::{
+    loaders::{BorrowedStringLoader, F64Arr3Loader, PrimitiveLoader},
+    ObjectProperties,
+};
+
+/// Generic named-property accessors.
+///
+/// These complement the curated getters generated by
+/// `impl_prop_proxy_getters!` by letting callers read arbitrary
+/// vendor/exporter-specific properties by name. Each accessor looks the
+/// property up and runs the matching loader, returning `None` when the
+/// property is absent or fails to load as the requested type.
+impl<'a> ObjectProperties<'a> {
+    /// Returns the named property as an `f32`.
+    pub fn get_f32(&self, name: &str) -> Option<f32> {
+        self.get_property(name)
+            .and_then(|p| p.load_value(PrimitiveLoader::<f32>::new()).ok())
+    }
+
+    /// Returns the named property as an `f64`.
+    pub fn get_f64(&self, name: &str) -> Option<f64> {
+        self.get_property(name)
+            .and_then(|p| p.load_value(PrimitiveLoader::<f64>::new()).ok())
+    }
+
+    /// Returns the named property as a `u32`.
+    pub fn get_u32(&self, name: &str) -> Option<u32> {
+        self.get_property(name)
+            .and_then(|p| p.load_value(PrimitiveLoader::<u32>::new()).ok())
+    }
+
+    /// Returns the named property as an `i32`.
+    pub fn get_i32(&self, name: &str) -> Option<i32> {
+        self.get_property(name)
+            .and_then(|p| p.load_value(PrimitiveLoader::<i32>::new()).ok())
+    }
+
+    /// Returns the named property as a `bool`.
+    pub fn get_bool(&self, name: &str) -> Option<bool> {
+        self.get_property(name)
+            .and_then(|p| p.load_value(PrimitiveLoader::<bool>::new()).ok())
+    }
+
+    /// Returns the named property as a borrowed string.
+    pub fn get_string(&self, name: &str) -> Option<&'a str> {
+        self.get_property(name)
+            .and_then(|p| p.load_value(BorrowedStringLoader::new()).ok())
+    }
+
+    /// Returns the named property as a 3-component `f64` vector.
+    pub fn get_vec3(&self, name: &str) -> Option<[f64; 3]> {
+        self.get_property(name)
+            .and_then(|p| p.load_value(F64Arr3Loader::new()).ok())
+    }
+}